@@ -14,8 +14,8 @@
 use std::collections::HashMap;
 use std::fs;
 use std::io::{self, Write};
-use serde::{Deserialize, Serialize};
-use chrono::Utc;
+use serde::{Deserialize, Deserializer, Serialize};
+use chrono::{DateTime, Utc};
 
 const VERSION: &str = "1.0.0";
 const AUTHOR: &str = "Network Theory Applied Research Institute";
@@ -23,6 +23,10 @@ const LICENSE: &str = "AGPL-3.0";
 
 const DEFAULT_CATEGORIES: &[&str] = &["reliability", "usability", "performance", "support"];
 
+// Pseudo-observation count for the Bayesian shrinkage estimator: how many
+// "votes" of the system average a brand-new exchange starts out weighted by.
+const DEFAULT_SHRINKAGE_CONFIDENCE: f64 = 5.0;
+
 fn rating_descriptions() -> HashMap<i8, &'static str> {
     let mut map = HashMap::new();
     map.insert(-1, "No Trust - User was harmed, exploited, or received a product or service with no discipline or malicious intent.");
@@ -40,12 +44,45 @@ struct Metadata {
     total_ratings: usize,
 }
 
+// A single rating plus the moment it was given. `Deserialize` is implemented
+// by hand so files written before ratings carried timestamps (a bare `i8` in
+// the array) still load: a legacy integer migrates in timestamped with the
+// load time, since no earlier record of it exists.
+#[derive(Debug, Clone, Copy, Serialize)]
+struct RatingEntry {
+    value: i8,
+    at: DateTime<Utc>,
+}
+
+impl<'de> Deserialize<'de> for RatingEntry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Legacy(i8),
+            Timestamped { value: i8, at: DateTime<Utc> },
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Legacy(value) => Ok(RatingEntry { value, at: Utc::now() }),
+            Repr::Timestamped { value, at } => Ok(RatingEntry { value, at }),
+        }
+    }
+}
+
+// `ratings` is flattened so the on-disk shape stays `{"reliability": [...],
+// "usability": [...], ..., "_metadata": {...}}` regardless of which category
+// names are in play. This also means files written by older versions, which
+// hardcoded the four named fields, need no key migration: those field names
+// just become ordinary keys in the map, and their bare-integer ratings are
+// migrated by `RatingEntry`'s `Deserialize`.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct ExchangeData {
-    reliability: Vec<i8>,
-    usability: Vec<i8>,
-    performance: Vec<i8>,
-    support: Vec<i8>,
+    #[serde(flatten)]
+    ratings: HashMap<String, Vec<RatingEntry>>,
     #[serde(rename = "_metadata")]
     metadata: Metadata,
 }
@@ -53,10 +90,7 @@ struct ExchangeData {
 impl ExchangeData {
     fn new() -> Self {
         ExchangeData {
-            reliability: Vec::new(),
-            usability: Vec::new(),
-            performance: Vec::new(),
-            support: Vec::new(),
+            ratings: HashMap::new(),
             metadata: Metadata {
                 created: Utc::now().to_rfc3339(),
                 total_ratings: 0,
@@ -64,24 +98,12 @@ impl ExchangeData {
         }
     }
 
-    fn get_category_mut(&mut self, category: &str) -> Option<&mut Vec<i8>> {
-        match category {
-            "reliability" => Some(&mut self.reliability),
-            "usability" => Some(&mut self.usability),
-            "performance" => Some(&mut self.performance),
-            "support" => Some(&mut self.support),
-            _ => None,
-        }
+    fn get_category_mut(&mut self, category: &str) -> Option<&mut Vec<RatingEntry>> {
+        Some(self.ratings.entry(category.to_string()).or_default())
     }
 
-    fn get_category(&self, category: &str) -> Option<&Vec<i8>> {
-        match category {
-            "reliability" => Some(&self.reliability),
-            "usability" => Some(&self.usability),
-            "performance" => Some(&self.performance),
-            "support" => Some(&self.support),
-            _ => None,
-        }
+    fn get_category(&self, category: &str) -> Option<&Vec<RatingEntry>> {
+        self.ratings.get(category)
     }
 }
 
@@ -93,13 +115,29 @@ struct StorageData {
 
 #[derive(Debug)]
 struct RatingSummary {
-    ratings: HashMap<String, Option<f64>>,
+    ratings: HashMap<String, Option<RatingScore>>,
+}
+
+// A raw arithmetic mean alongside its Bayesian-shrinkage-adjusted
+// counterpart, so low-volume exchanges/categories can be shown honestly
+// without hiding the underlying raw number.
+#[derive(Debug, Clone, Copy)]
+struct RatingScore {
+    raw: f64,
+    adjusted: f64,
 }
 
 #[derive(Debug)]
+struct CsvImportReport {
+    imported: usize,
+    errors: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
 struct ExchangePerformance {
     name: String,
-    average: f64,
+    raw_average: f64,
+    adjusted_average: f64,
 }
 
 #[derive(Debug)]
@@ -107,27 +145,65 @@ struct SystemReport {
     total_exchanges: usize,
     total_ratings: usize,
     system_average: Option<f64>,
-    category_averages: HashMap<String, Option<f64>>,
+    category_averages: HashMap<String, Option<RatingScore>>,
     top_performers: Vec<ExchangePerformance>,
     bottom_performers: Vec<ExchangePerformance>,
 }
 
+#[derive(Debug)]
+struct TrendBucket {
+    period: String,
+    average: f64,
+    count: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TrendDirection {
+    Improving,
+    Declining,
+    Stable,
+}
+
+impl std::fmt::Display for TrendDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let label = match self {
+            TrendDirection::Improving => "Improving",
+            TrendDirection::Declining => "Declining",
+            TrendDirection::Stable => "Stable",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+#[derive(Debug)]
+struct TrendReport {
+    buckets: Vec<TrendBucket>,
+    slope: f64,
+    direction: TrendDirection,
+}
+
 struct LevesonRatingSystem {
     categories: Vec<String>,
     storage_path: Option<String>,
     exchanges: HashMap<String, ExchangeData>,
+    // "Pseudo-observations" of the system average (C in the shrinkage
+    // formula `(C*m + S) / (C + n)`); higher means more evidence is needed
+    // before an exchange's own ratings outweigh the system-wide prior.
+    confidence: f64,
 }
 
 impl LevesonRatingSystem {
-    fn new(storage_path: Option<String>, categories: Option<Vec<String>>) -> Self {
+    fn new(storage_path: Option<String>, categories: Option<Vec<String>>, confidence: Option<f64>) -> Self {
         let categories = categories.unwrap_or_else(|| {
             DEFAULT_CATEGORIES.iter().map(|s| s.to_string()).collect()
         });
+        let confidence = confidence.unwrap_or(DEFAULT_SHRINKAGE_CONFIDENCE);
 
         let mut system = LevesonRatingSystem {
             categories,
             storage_path,
             exchanges: HashMap::new(),
+            confidence,
         };
 
         if system.storage_path.is_some() {
@@ -137,6 +213,34 @@ impl LevesonRatingSystem {
         system
     }
 
+    // Global prior mean `m` over every rating in the system, used as the
+    // shrinkage target. `None` when there are no ratings at all.
+    fn global_mean(&self) -> Option<f64> {
+        let mut sum: i64 = 0;
+        let mut count: usize = 0;
+
+        for exchange in self.exchanges.values() {
+            for category in &self.categories {
+                if let Some(ratings) = exchange.get_category(category) {
+                    sum += ratings.iter().map(|r| r.value as i64).sum::<i64>();
+                    count += ratings.len();
+                }
+            }
+        }
+
+        if count > 0 {
+            Some(sum as f64 / count as f64)
+        } else {
+            None
+        }
+    }
+
+    // `(C*m + S) / (C + n)`: pulls a low-count sum `S` over `n` ratings
+    // toward the system prior `m` until enough evidence accumulates.
+    fn shrink(&self, m: f64, sum: i64, n: usize) -> f64 {
+        (self.confidence * m + sum as f64) / (self.confidence + n as f64)
+    }
+
     fn load_from_file(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         if let Some(path) = &self.storage_path {
             if std::path::Path::new(path).exists() {
@@ -182,7 +286,7 @@ impl LevesonRatingSystem {
         }
 
         if let Some(category) = exchange.get_category_mut(criterion) {
-            category.push(rating);
+            category.push(RatingEntry { value: rating, at: Utc::now() });
             exchange.metadata.total_ratings += 1;
             self.save_to_file().map_err(|e| e.to_string())?;
             Ok(())
@@ -241,15 +345,22 @@ impl LevesonRatingSystem {
         let exchange = self.exchanges.get(name)
             .ok_or_else(|| format!("Exchange '{}' does not exist", name))?;
 
+        let m = self.global_mean().unwrap_or(0.0);
         let mut ratings = HashMap::new();
 
         for criterion in &self.categories {
-            if let Some(category) = exchange.get_category(criterion) {
-                if !category.is_empty() {
-                    let sum: i32 = category.iter().map(|&x| x as i32).sum();
-                    let avg = sum as f64 / category.len() as f64;
-                    ratings.insert(criterion.clone(), Some((avg * 100.0).round() / 100.0));
-                } else {
+            match exchange.get_category(criterion) {
+                Some(category) if !category.is_empty() => {
+                    let sum: i64 = category.iter().map(|r| r.value as i64).sum();
+                    let n = category.len();
+                    let raw = sum as f64 / n as f64;
+                    let adjusted = self.shrink(m, sum, n);
+                    ratings.insert(criterion.clone(), Some(RatingScore {
+                        raw: (raw * 100.0).round() / 100.0,
+                        adjusted: (adjusted * 100.0).round() / 100.0,
+                    }));
+                }
+                _ => {
                     ratings.insert(criterion.clone(), None);
                 }
             }
@@ -282,56 +393,63 @@ impl LevesonRatingSystem {
             category_totals.insert(category.clone(), Vec::new());
         }
 
-        let mut exchange_averages: HashMap<String, f64> = HashMap::new();
+        // (sum, count) of every rating an exchange has across all categories.
+        let mut exchange_totals: HashMap<String, (i64, usize)> = HashMap::new();
 
         for (exchange_name, exchange_data) in &self.exchanges {
-            let mut exchange_ratings = Vec::new();
+            let mut sum: i64 = 0;
+            let mut n: usize = 0;
 
             for category in &self.categories {
                 if let Some(ratings) = exchange_data.get_category(category) {
                     if !ratings.is_empty() {
-                        let sum: i32 = ratings.iter().map(|&x| x as i32).sum();
-                        let avg = sum as f64 / ratings.len() as f64;
-                        exchange_ratings.push(avg);
-                        category_totals.get_mut(category).unwrap().extend(ratings);
-                        all_ratings.extend(ratings);
+                        let values = ratings.iter().map(|r| r.value);
+                        category_totals.get_mut(category).unwrap().extend(values.clone());
+                        all_ratings.extend(values);
+                        sum += ratings.iter().map(|r| r.value as i64).sum::<i64>();
+                        n += ratings.len();
                     }
                 }
             }
 
-            if !exchange_ratings.is_empty() {
-                let sum: f64 = exchange_ratings.iter().sum();
-                exchange_averages.insert(exchange_name.clone(), sum / exchange_ratings.len() as f64);
+            if n > 0 {
+                exchange_totals.insert(exchange_name.clone(), (sum, n));
             }
         }
 
         let system_average = if !all_ratings.is_empty() {
-            let sum: i32 = all_ratings.iter().map(|&x| x as i32).sum();
+            let sum: i64 = all_ratings.iter().map(|&x| x as i64).sum();
             Some(sum as f64 / all_ratings.len() as f64)
         } else {
             None
         };
+        let m = system_average.unwrap_or(0.0);
 
         let mut category_averages = HashMap::new();
         for (category, ratings) in &category_totals {
             if !ratings.is_empty() {
-                let sum: i32 = ratings.iter().map(|&x| x as i32).sum();
-                category_averages.insert(category.clone(), Some(sum as f64 / ratings.len() as f64));
+                let sum: i64 = ratings.iter().map(|&x| x as i64).sum();
+                let n = ratings.len();
+                category_averages.insert(category.clone(), Some(RatingScore {
+                    raw: sum as f64 / n as f64,
+                    adjusted: self.shrink(m, sum, n),
+                }));
             } else {
                 category_averages.insert(category.clone(), None);
             }
         }
 
-        let mut performances: Vec<ExchangePerformance> = exchange_averages.iter()
-            .map(|(name, &average)| ExchangePerformance {
+        let mut performances: Vec<ExchangePerformance> = exchange_totals.iter()
+            .map(|(name, &(sum, n))| ExchangePerformance {
                 name: name.clone(),
-                average,
+                raw_average: sum as f64 / n as f64,
+                adjusted_average: self.shrink(m, sum, n),
             })
             .collect();
 
-        performances.sort_by(|a, b| b.average.partial_cmp(&a.average).unwrap());
+        performances.sort_by(|a, b| b.adjusted_average.partial_cmp(&a.adjusted_average).unwrap());
 
-        let top_performers = performances.iter().take(5).cloned().map(|p| p).collect();
+        let top_performers = performances.iter().take(5).cloned().collect();
         let bottom_performers: Vec<ExchangePerformance> = performances.iter()
             .rev()
             .take(5)
@@ -351,6 +469,86 @@ impl LevesonRatingSystem {
         }
     }
 
+    // Buckets an exchange's ratings (across all categories) by period and
+    // reports each bucket's average plus the direction the score is
+    // heading, via the slope of a simple linear regression over the
+    // buckets in order. `window` is "daily", "weekly", or anything else
+    // (including the default "monthly").
+    fn trend(&self, exchange_name: &str, window: &str) -> Result<TrendReport, String> {
+        let exchange = self.exchanges.get(exchange_name)
+            .ok_or_else(|| format!("Exchange '{}' does not exist", exchange_name))?;
+
+        let mut buckets: HashMap<String, (i64, usize)> = HashMap::new();
+        for category in &self.categories {
+            if let Some(ratings) = exchange.get_category(category) {
+                for entry in ratings {
+                    let slot = buckets.entry(Self::bucket_key(&entry.at, window)).or_insert((0, 0));
+                    slot.0 += entry.value as i64;
+                    slot.1 += 1;
+                }
+            }
+        }
+
+        let mut periods: Vec<String> = buckets.keys().cloned().collect();
+        periods.sort();
+
+        let bucket_list: Vec<TrendBucket> = periods.iter()
+            .map(|period| {
+                let (sum, count) = buckets[period];
+                TrendBucket {
+                    period: period.clone(),
+                    average: sum as f64 / count as f64,
+                    count,
+                }
+            })
+            .collect();
+
+        let slope = Self::trend_slope(&bucket_list);
+        let direction = if slope > 0.05 {
+            TrendDirection::Improving
+        } else if slope < -0.05 {
+            TrendDirection::Declining
+        } else {
+            TrendDirection::Stable
+        };
+
+        Ok(TrendReport { buckets: bucket_list, slope, direction })
+    }
+
+    fn bucket_key(at: &DateTime<Utc>, window: &str) -> String {
+        match window {
+            "daily" => at.format("%Y-%m-%d").to_string(),
+            "weekly" => at.format("%G-W%V").to_string(),
+            _ => at.format("%Y-%m").to_string(),
+        }
+    }
+
+    // Ordinary-least-squares slope of bucket average against bucket index,
+    // i.e. how much the average moves per period.
+    fn trend_slope(buckets: &[TrendBucket]) -> f64 {
+        let n = buckets.len();
+        if n < 2 {
+            return 0.0;
+        }
+
+        let x_mean = (n - 1) as f64 / 2.0;
+        let y_mean = buckets.iter().map(|b| b.average).sum::<f64>() / n as f64;
+
+        let mut numerator = 0.0;
+        let mut denominator = 0.0;
+        for (i, bucket) in buckets.iter().enumerate() {
+            let dx = i as f64 - x_mean;
+            numerator += dx * (bucket.average - y_mean);
+            denominator += dx * dx;
+        }
+
+        if denominator == 0.0 {
+            0.0
+        } else {
+            numerator / denominator
+        }
+    }
+
     fn export_to_json(&self, output_path: &str) -> Result<(), Box<dyn std::error::Error>> {
         let storage = StorageData {
             exchanges: self.exchanges.clone(),
@@ -367,11 +565,11 @@ impl LevesonRatingSystem {
         for (exchange_name, exchange_data) in &self.exchanges {
             for category in &self.categories {
                 if let Some(ratings) = exchange_data.get_category(category) {
-                    for (i, &rating) in ratings.iter().enumerate() {
+                    for (i, entry) in ratings.iter().enumerate() {
                         wtr.write_record(&[
                             exchange_name,
                             category,
-                            &rating.to_string(),
+                            &entry.value.to_string(),
                             &(i + 1).to_string(),
                         ])?;
                     }
@@ -382,6 +580,140 @@ impl LevesonRatingSystem {
         wtr.flush()?;
         Ok(())
     }
+
+    // Emits a Graphviz `digraph` connecting exchanges to the categories
+    // they've been rated on, edge labels carrying the category average and
+    // node fill color running red (near -1) to green (near 4) by the
+    // exchange's overall raw average. Renders directly with `dot -Tsvg`.
+    fn export_to_dot(&self, output_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let mut dot = String::new();
+        dot.push_str("digraph lbtas {\n");
+        dot.push_str("    rankdir=LR;\n");
+        dot.push_str("    node [style=filled];\n\n");
+
+        for (exchange_name, exchange_data) in &self.exchanges {
+            let mut sum: i64 = 0;
+            let mut n: usize = 0;
+            for category in &self.categories {
+                if let Some(ratings) = exchange_data.get_category(category) {
+                    sum += ratings.iter().map(|r| r.value as i64).sum::<i64>();
+                    n += ratings.len();
+                }
+            }
+            let overall = if n > 0 { Some(sum as f64 / n as f64) } else { None };
+            dot.push_str(&format!(
+                "    \"{}\" [fillcolor=\"{}\"];\n",
+                exchange_name,
+                Self::score_to_color(overall),
+            ));
+        }
+        dot.push('\n');
+
+        for category in &self.categories {
+            dot.push_str(&format!("    \"{}\" [shape=box, style=dashed, fillcolor=white];\n", category));
+        }
+        dot.push('\n');
+
+        for (exchange_name, exchange_data) in &self.exchanges {
+            for category in &self.categories {
+                if let Some(ratings) = exchange_data.get_category(category) {
+                    if !ratings.is_empty() {
+                        let sum: i64 = ratings.iter().map(|r| r.value as i64).sum();
+                        let avg = sum as f64 / ratings.len() as f64;
+                        dot.push_str(&format!(
+                            "    \"{}\" -> \"{}\" [label=\"{:.2}\"];\n",
+                            exchange_name, category, avg,
+                        ));
+                    }
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        fs::write(output_path, dot)?;
+        Ok(())
+    }
+
+    // Maps a -1..=4 LBTAS score to a red-to-green hex fill color; unrated
+    // exchanges get a neutral gray.
+    fn score_to_color(score: Option<f64>) -> String {
+        match score {
+            None => "#cccccc".to_string(),
+            Some(s) => {
+                let t = (s.clamp(-1.0, 4.0) + 1.0) / 5.0;
+                let red = ((1.0 - t) * 220.0) as u8;
+                let green = (t * 200.0) as u8;
+                format!("#{:02x}{:02x}50", red, green)
+            }
+        }
+    }
+
+    // Mirrors `export_to_csv`'s `exchange,category,rating,index` shape. Bad
+    // rows are collected with their source line number rather than aborting
+    // the whole import, so one typo in a spreadsheet doesn't cost every
+    // other row.
+    fn import_from_csv(&mut self, path: &str) -> Result<CsvImportReport, Box<dyn std::error::Error>> {
+        let mut rdr = csv::Reader::from_path(path)?;
+        let mut imported = 0;
+        let mut errors = Vec::new();
+
+        for (i, result) in rdr.records().enumerate() {
+            let line = i + 2; // +1 for 0-index, +1 for the header row
+            let record = match result {
+                Ok(record) => record,
+                Err(e) => {
+                    errors.push(format!("line {}: {}", line, e));
+                    continue;
+                }
+            };
+
+            let exchange = match record.get(0) {
+                Some(v) if !v.is_empty() => v,
+                _ => {
+                    errors.push(format!("line {}: missing exchange", line));
+                    continue;
+                }
+            };
+
+            let category = match record.get(1) {
+                Some(v) if self.categories.contains(&v.to_string()) => v,
+                Some(v) => {
+                    errors.push(format!("line {}: category '{}' not in valid categories: {:?}", line, v, self.categories));
+                    continue;
+                }
+                None => {
+                    errors.push(format!("line {}: missing category", line));
+                    continue;
+                }
+            };
+
+            let rating: i8 = match record.get(2).map(|v| v.parse()) {
+                Some(Ok(r)) if (-1..=4).contains(&r) => r,
+                Some(Ok(r)) => {
+                    errors.push(format!("line {}: rating must be between -1 and 4, got {}", line, r));
+                    continue;
+                }
+                Some(Err(_)) => {
+                    errors.push(format!("line {}: invalid rating '{}'", line, record.get(2).unwrap_or("")));
+                    continue;
+                }
+                None => {
+                    errors.push(format!("line {}: missing rating", line));
+                    continue;
+                }
+            };
+
+            self.exchanges.entry(exchange.to_string()).or_insert_with(ExchangeData::new);
+            let data = self.exchanges.get_mut(exchange).unwrap();
+            data.get_category_mut(category).unwrap().push(RatingEntry { value: rating, at: Utc::now() });
+            data.metadata.total_ratings += 1;
+            imported += 1;
+        }
+
+        self.save_to_file()?;
+
+        Ok(CsvImportReport { imported, errors })
+    }
 }
 
 fn main() {
@@ -395,13 +727,15 @@ fn main() {
         println!("  lbtas view <exchange>");
         println!("  lbtas list");
         println!("  lbtas report");
-        println!("  lbtas export <format> <output>");
+        println!("  lbtas export <json|csv|dot> <output>");
+        println!("  lbtas import csv <file>");
+        println!("  lbtas trend <exchange> [daily|weekly|monthly]");
         return;
     }
 
     let command = &args[1];
     let storage = "lbtas_ratings.json".to_string();
-    let mut system = LevesonRatingSystem::new(Some(storage), None);
+    let mut system = LevesonRatingSystem::new(Some(storage), None, None);
 
     match command.as_str() {
         "rate" => {
@@ -464,7 +798,7 @@ fn main() {
                     for category in &system.categories {
                         if let Some(rating) = summary.ratings.get(category) {
                             match rating {
-                                Some(r) => println!("{:12}: {:4.2}", category, r),
+                                Some(r) => println!("{:12}: {:4.2} (adjusted: {:4.2})", category, r.raw, r.adjusted),
                                 None => println!("{:12}: No ratings", category),
                             }
                         }
@@ -485,12 +819,12 @@ fn main() {
                 println!("Registered exchanges:");
                 for exchange in exchanges {
                     if let Ok(summary) = system.view_ratings(&exchange) {
-                        let values: Vec<f64> = summary.ratings.values()
+                        let scores: Vec<RatingScore> = summary.ratings.values()
                             .filter_map(|&r| r)
                             .collect();
-                        if !values.is_empty() {
-                            let avg = values.iter().sum::<f64>() / values.len() as f64;
-                            println!("  {} (avg: {:.2})", exchange, avg);
+                        if !scores.is_empty() {
+                            let adjusted = scores.iter().map(|s| s.adjusted).sum::<f64>() / scores.len() as f64;
+                            println!("  {} (adjusted avg: {:.2})", exchange, adjusted);
                         } else {
                             println!("  {} (no ratings)", exchange);
                         }
@@ -510,20 +844,25 @@ fn main() {
             }
 
             if !report.category_averages.is_empty() {
-                println!("\nCategory Averages:");
+                println!("\nCategory Averages (raw / adjusted):");
                 for category in &system.categories {
-                    if let Some(avg) = report.category_averages.get(category) {
-                        if let Some(a) = avg {
-                            println!("  {:12}: {:.2}", category, a);
-                        }
+                    if let Some(Some(a)) = report.category_averages.get(category) {
+                        println!("  {:12}: {:.2} / {:.2}", category, a.raw, a.adjusted);
                     }
                 }
             }
 
             if !report.top_performers.is_empty() {
-                println!("\nTop Performers:");
+                println!("\nTop Performers (adjusted):");
                 for perf in &report.top_performers {
-                    println!("  {}: {:.2}", perf.name, perf.average);
+                    println!("  {}: {:.2} (raw: {:.2})", perf.name, perf.adjusted_average, perf.raw_average);
+                }
+            }
+
+            if !report.bottom_performers.is_empty() {
+                println!("\nBottom Performers (adjusted):");
+                for perf in &report.bottom_performers {
+                    println!("  {}: {:.2} (raw: {:.2})", perf.name, perf.adjusted_average, perf.raw_average);
                 }
             }
         }
@@ -539,8 +878,9 @@ fn main() {
             let result = match format.as_str() {
                 "json" => system.export_to_json(output),
                 "csv" => system.export_to_csv(output),
+                "dot" => system.export_to_dot(output),
                 _ => {
-                    eprintln!("Error: format must be json or csv");
+                    eprintln!("Error: format must be json, csv, or dot");
                     std::process::exit(1);
                 }
             };
@@ -552,6 +892,65 @@ fn main() {
             println!("Exported to {}", output);
         }
 
+        "import" => {
+            if args.len() < 4 {
+                eprintln!("Error: format and input path required");
+                std::process::exit(1);
+            }
+            let format = &args[2];
+            let input = &args[3];
+
+            match format.as_str() {
+                "csv" => match system.import_from_csv(input) {
+                    Ok(report) => {
+                        println!("Imported {} rating(s) from {}", report.imported, input);
+                        if !report.errors.is_empty() {
+                            println!("{} row(s) skipped:", report.errors.len());
+                            for error in &report.errors {
+                                println!("  {}", error);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                },
+                _ => {
+                    eprintln!("Error: format must be csv");
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        "trend" => {
+            if args.len() < 3 {
+                eprintln!("Error: Exchange name required");
+                std::process::exit(1);
+            }
+            let exchange = &args[2];
+            let window = args.get(3).map(|s| s.as_str()).unwrap_or("monthly");
+
+            match system.trend(exchange, window) {
+                Ok(report) => {
+                    println!("\nTrend for '{}' ({})", exchange, window);
+                    println!("{}", "=".repeat(40));
+                    if report.buckets.is_empty() {
+                        println!("No ratings yet.");
+                    } else {
+                        for bucket in &report.buckets {
+                            println!("  {:10}: {:4.2} ({} rating(s))", bucket.period, bucket.average, bucket.count);
+                        }
+                        println!("\nDirection: {} (slope {:.3}/period)", report.direction, report.slope);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+
         _ => {
             eprintln!("Unknown command: {}", command);
             std::process::exit(1);